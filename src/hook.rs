@@ -1,11 +1,23 @@
+pub mod config;
+pub mod event;
+mod hotkey;
+pub mod inject;
 pub(super) mod inner;
+mod keycode;
+mod registry;
 
-use crate::hook::inner::{setup_keyboard_hook, setup_mouse_hook, InnerHook};
+use crate::hook::config::Binding;
+use crate::hook::inner::{setup_keyboard_hook, setup_mouse_hook, ActiveGuard, InnerHook};
 use std::sync::Arc;
 
+pub use config::ConfigError;
+pub use event::InputEvent;
+pub use keycode::KeyCode;
+pub use registry::Trigger;
+
 /// Handle to a low-level Windows hook for keyboard and/or mouse events, regardless of application focus.
 /// For more details see the HookBuilder. When the handle goes out of scope, then the low-level hook is removed.
-/// 
+///
 /// Example
 /// ```rust
 /// # fn main() {
@@ -21,12 +33,16 @@ use std::sync::Arc;
 pub struct Hook {
     _keyboard_hook: Option<Arc<InnerHook>>,
     _mouse_hook: Option<Arc<InnerHook>>,
+    // Declared after the inner hooks so it is dropped after them: the hook slot stays taken
+    // until both low-level hooks are gone.
+    _guard: ActiveGuard,
+    config_bindings: Vec<Binding>,
 }
 
 impl Hook {
-    /// Tries to receive an event from the low-level hook(s) running in the background thread(s).
+    /// Tries to receive an [InputEvent] from the low-level hook(s) running in the background thread(s).
     /// If there are no events at the moment, will return Err(std::sync::mpsc::Empty):
-    /// 
+    ///
     /// ```rust
     /// # fn main() {
     /// # use monke::hook::HookBuilder;
@@ -38,36 +54,106 @@ impl Hook {
     /// assert_eq!(hook.try_recv().err(), Some(TryRecvError::Empty));
     /// # }
     /// ```
-    /// 
-    /// Hook::try_recv() should be treated as a foundation for more complex processing. 
-    /// For example if one would be intereted in only unique key presses
+    ///
+    /// Hook::try_recv() should be treated as a foundation for more complex processing.
+    /// For example if one would be intereted in only key releases
     /// with timestamps (regardless of how long the key press lasts):
-    /// 
+    ///
     /// ``` rust
     /// # fn main() {
-    /// # use monke::hook::{KeyCode, HookBuilder};
+    /// # use monke::hook::{InputEvent, event::KeyState, HookBuilder};
     /// # let hook = HookBuilder::new().with_mouse().build().unwrap();
     /// use std::sync::mpsc::channel;
     /// use std::time::Instant;
     /// let (event_sender, _event_receiver) = channel();
     /// while let Ok(event) = hook.try_recv() {
-    ///     // Process only "press ups" to find unique key presses,
+    ///     // Process only "key up" to find unique key presses,
     ///     // because if a user holds a key, then Windows can emit multiple "key down" events
-    ///     if event == KeyCode::Up {
+    ///     if let InputEvent::Keyboard { state: KeyState::Released, .. } = event {
     ///         event_sender.send( (event, Instant::now() ));
     ///     }
     /// }
     /// # }
     /// ```
-    pub fn try_recv(&self) -> Result<KeyCode, std::sync::mpsc::TryRecvError> {
+    pub fn try_recv(&self) -> Result<InputEvent, std::sync::mpsc::TryRecvError> {
         InnerHook::try_recv()
     }
+
+    /// Runs `callback` whenever an [InputEvent] matching `trigger` arrives, instead of requiring
+    /// the caller to poll [Hook::try_recv] in a loop. Callbacks run on a dedicated background
+    /// dispatcher thread, not the hook thread, so slow user code can't make Windows time out the
+    /// low-level hook.
+    ///
+    /// ```rust
+    /// # fn main() {
+    /// # use monke::hook::{HookBuilder, KeyCode, Trigger};
+    /// let hook = HookBuilder::new().with_keyboard().build().unwrap();
+    /// hook.bind(Trigger::Key(KeyCode::Escape), |event| {
+    ///     println!("escape pressed or released: {:?}", event);
+    /// });
+    /// # }
+    /// ```
+    pub fn bind(&self, trigger: Trigger, callback: impl Fn(InputEvent) + Send + Sync + 'static) {
+        registry::bind(trigger, callback);
+    }
+
+    /// Runs `callback` for every [InputEvent], regardless of [Trigger]. See [Hook::bind] for the
+    /// dispatcher thread details.
+    pub fn bind_any(&self, callback: impl Fn(InputEvent) + Send + Sync + 'static) {
+        registry::bind_any(callback);
+    }
+
+    /// Runs `callback` exactly once whenever every key in `keys` becomes held at the same time,
+    /// in any order. The combination will not fire again until at least one of its keys is
+    /// released and the whole combination is re-formed.
+    ///
+    /// ```rust
+    /// # fn main() {
+    /// # use monke::hook::{HookBuilder, KeyCode};
+    /// let hook = HookBuilder::new().with_keyboard().build().unwrap();
+    /// hook.register_hotkey(&[KeyCode::LeftControl, KeyCode::LeftShift, KeyCode::K], || {
+    ///     println!("Ctrl+Shift+K pressed");
+    /// });
+    /// # }
+    /// ```
+    pub fn register_hotkey(&self, keys: &[KeyCode], callback: impl Fn() + Send + Sync + 'static) {
+        hotkey::register(keys, callback);
+    }
+
+    /// Attaches `callback` to the key combination bound to `name` by a config file loaded via
+    /// [HookBuilder::with_config]. Returns `false` (and registers nothing) if no loaded config
+    /// binds that action name.
+    ///
+    /// ```rust
+    /// # fn main() {
+    /// # use monke::hook::HookBuilder;
+    /// # let path = "hotkeys.conf";
+    /// # std::fs::write(path, "CTRL+SHIFT+R = reload").unwrap();
+    /// let hook = HookBuilder::new()
+    ///     .with_keyboard()
+    ///     .with_config(path)
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
+    /// hook.on_action("reload", || println!("reloading"));
+    /// # std::fs::remove_file(path).unwrap();
+    /// # }
+    /// ```
+    pub fn on_action(&self, name: &str, callback: impl Fn() + Send + Sync + 'static) -> bool {
+        match self.config_bindings.iter().find(|b| b.action == name) {
+            Some(binding) => {
+                hotkey::register(&binding.keys, callback);
+                true
+            }
+            None => false,
+        }
+    }
 }
 
 /// The only way to build a hook is to use HookBuilder.
 /// It is possible to choose what types of hooks are active.
 /// Currently only "mouse" and "keyboard" hooks are supported (due to Windows API restrictions).
-/// 
+///
 /// # Build hook for both mouse and keyboard:
 /// ```rust
 /// use monke::hook::HookBuilder;
@@ -79,9 +165,9 @@ impl Hook {
 ///     assert!(hook.is_some());
 /// }
 /// ```
-/// 
+///
 /// # Limitations
-/// 
+///
 /// At least one hook type has to be specified, otherwise build will fail:
 /// ```rust
 /// # fn main() {
@@ -91,14 +177,14 @@ impl Hook {
 /// # }
 /// ```
 /// There can be only one hook at the moment, even if we try to create different type:
-/// 
+///
 /// ```rust
 /// # fn main() {
 /// # use monke::hook::HookBuilder;
 /// let hook = HookBuilder::new()
 ///             .with_mouse()
 ///             .build();
-/// 
+///
 /// assert!(hook.is_some());
 /// // Building second hook while the first one is still in scope will fail.
 /// // Even if that second hook is keyboard hook:
@@ -106,16 +192,16 @@ impl Hook {
 /// assert!(another_hook.is_none());
 /// # }
 /// ```
-/// 
+///
 /// Only after the old hook is dropped, the new one can be created:
-/// 
+///
 /// ```rust
 /// # fn main() {
 /// # use monke::hook::HookBuilder;
 /// let hook = HookBuilder::new()
 ///             .with_mouse()
 ///             .build();
-/// 
+///
 /// assert!(hook.is_some());
 /// // It could go out of scope as well, but let's drop it explicitly:
 /// drop(hook);
@@ -127,6 +213,8 @@ impl Hook {
 pub struct HookBuilder {
     mouse: bool,
     keyboard: bool,
+    suppression: Option<Box<dyn Fn(&InputEvent) -> bool + Send + Sync + 'static>>,
+    config_bindings: Vec<Binding>,
 }
 
 impl HookBuilder {
@@ -134,6 +222,8 @@ impl HookBuilder {
         Self {
             mouse: false,
             keyboard: false,
+            suppression: None,
+            config_bindings: Vec::new(),
         }
     }
 
@@ -149,11 +239,45 @@ impl HookBuilder {
         self
     }
 
+    /// Opts into suppression: events for which `predicate` returns `true` never reach other
+    /// applications (or the rest of the OS), instead of merely being observed.
+    ///
+    /// **The predicate runs synchronously on the hook thread, inside the low-level hook
+    /// procedure, before the event is even handed to [Hook::try_recv]/[Hook::bind]. It must be
+    /// fast** — Windows silently removes low-level hooks that take too long to return (by
+    /// default, around 300ms), at which point *nothing* from this hook, suppressed or not, is
+    /// delivered anymore.
+    ///
+    /// Without calling this, a [Hook] never suppresses anything: this crate stays strictly
+    /// read-only by default, as documented at the crate root.
+    pub fn with_suppression(
+        mut self,
+        predicate: impl Fn(&InputEvent) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.suppression = Some(Box::new(predicate));
+        self
+    }
+
+    /// Loads declarative hotkey bindings from a config file (see [config] for the format) and
+    /// merges them into this builder. Attach the actual callbacks after building via
+    /// [Hook::on_action]. Can be called more than once to merge bindings from several files.
+    pub fn with_config(mut self, path: impl AsRef<std::path::Path>) -> Result<Self, ConfigError> {
+        let loaded = config::load(path, &self.config_bindings)?;
+        self.config_bindings.extend(loaded);
+        Ok(self)
+    }
+
     /// Builds the requested hooks and returns common handle for them.
     /// If any hooks are active, then the build fails.
     pub fn build(self) -> Option<Hook> {
         if !self.keyboard && !self.mouse {
-            return None
+            return None;
+        }
+
+        let guard = inner::try_acquire()?;
+
+        if let Some(predicate) = self.suppression {
+            inner::install_suppression(predicate);
         }
 
         let kb_hook = if self.keyboard {
@@ -161,26 +285,17 @@ impl HookBuilder {
         } else {
             None
         };
-        let m_hook = if self.mouse {
-            setup_mouse_hook()
-        } else {
-            None
-        };
+        let m_hook = if self.mouse { setup_mouse_hook() } else { None };
 
         if kb_hook.is_none() && m_hook.is_none() {
             None
         } else {
             Some(Hook {
+                _guard: guard,
                 _keyboard_hook: kb_hook,
                 _mouse_hook: m_hook,
+                config_bindings: self.config_bindings,
             })
         }
     }
 }
-
-#[derive(Debug, PartialEq)]
-pub enum KeyCode {
-    Down,
-    Up,
-}
-
@@ -0,0 +1,352 @@
+//! Declarative hotkey bindings loaded from a simple text config file, so shortcuts can be
+//! reconfigured without recompiling.
+//!
+//! # Format
+//!
+//! One binding per line: a `+`-separated key combination, an `=`, and an action name.
+//! Blank lines and lines starting with `#` are ignored.
+//!
+//! ```text
+//! # reload action
+//! CTRL+SHIFT+R = reload
+//! CTRL+SHIFT+K = my_action
+//! ```
+//!
+//! Load a config with [crate::hook::HookBuilder::with_config], then attach the actual callback
+//! for each named action with [crate::hook::Hook::on_action].
+
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use crate::hook::KeyCode;
+
+/// A single parsed `keys = action` line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Binding {
+    pub(super) keys: Vec<KeyCode>,
+    pub(super) action: String,
+}
+
+/// Everything that can go wrong loading or parsing a hotkey config.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The config file itself couldn't be read.
+    Io(std::io::Error),
+    /// A key name on the left-hand side isn't one this crate recognizes.
+    UnknownKey { line: usize, name: String },
+    /// A line had no keys before the `=`, or no action name after it.
+    EmptyBinding { line: usize },
+    /// The same action name was bound more than once.
+    DuplicateBinding { line: usize, action: String },
+    /// A line wasn't of the form `keys = action`.
+    MalformedLine { line: usize },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(err) => write!(f, "could not read hotkey config: {err}"),
+            ConfigError::UnknownKey { line, name } => {
+                write!(f, "line {line}: unknown key name \"{name}\"")
+            }
+            ConfigError::EmptyBinding { line } => {
+                write!(f, "line {line}: binding has no keys or no action name")
+            }
+            ConfigError::DuplicateBinding { line, action } => {
+                write!(f, "line {line}: action \"{action}\" is already bound")
+            }
+            ConfigError::MalformedLine { line } => {
+                write!(f, "line {line}: expected \"KEY+KEY = action\"")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<std::io::Error> for ConfigError {
+    fn from(err: std::io::Error) -> Self {
+        ConfigError::Io(err)
+    }
+}
+
+/// Reads and parses the hotkey config at `path`. `existing` is checked alongside the file's own
+/// bindings for [ConfigError::DuplicateBinding], so merging a second file via
+/// [crate::hook::HookBuilder::with_config] can't silently shadow an action already bound by the
+/// first. See the module docs for the format.
+pub(super) fn load(
+    path: impl AsRef<Path>,
+    existing: &[Binding],
+) -> Result<Vec<Binding>, ConfigError> {
+    parse(&fs::read_to_string(path)?, existing)
+}
+
+fn parse(input: &str, existing: &[Binding]) -> Result<Vec<Binding>, ConfigError> {
+    let mut bindings = Vec::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (keys_part, action_part) = line
+            .split_once('=')
+            .ok_or(ConfigError::MalformedLine { line: line_number })?;
+
+        let action = action_part.trim().to_string();
+        let keys: Vec<KeyCode> = keys_part
+            .split('+')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(|name| {
+                key_from_name(name).ok_or_else(|| ConfigError::UnknownKey {
+                    line: line_number,
+                    name: name.to_string(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        if keys.is_empty() || action.is_empty() {
+            return Err(ConfigError::EmptyBinding { line: line_number });
+        }
+
+        if existing
+            .iter()
+            .chain(bindings.iter())
+            .any(|b: &Binding| b.action == action)
+        {
+            return Err(ConfigError::DuplicateBinding {
+                line: line_number,
+                action,
+            });
+        }
+
+        bindings.push(Binding { keys, action });
+    }
+
+    Ok(bindings)
+}
+
+/// Resolves a human key name (case-insensitive) to a [KeyCode]. `CTRL`/`SHIFT`/`ALT`/`WIN`
+/// resolve to their left-hand variant; use `LCTRL`/`RCTRL` etc. to be explicit about which side.
+fn key_from_name(name: &str) -> Option<KeyCode> {
+    let upper = name.to_ascii_uppercase();
+    Some(match upper.as_str() {
+        "CTRL" | "CONTROL" | "LCTRL" | "LCONTROL" => KeyCode::LeftControl,
+        "RCTRL" | "RCONTROL" => KeyCode::RightControl,
+        "SHIFT" | "LSHIFT" => KeyCode::LeftShift,
+        "RSHIFT" => KeyCode::RightShift,
+        "ALT" | "LALT" => KeyCode::LeftAlt,
+        "RALT" => KeyCode::RightAlt,
+        "WIN" | "LWIN" | "SUPER" => KeyCode::LeftWindows,
+        "RWIN" => KeyCode::RightWindows,
+        "ESC" | "ESCAPE" => KeyCode::Escape,
+        "TAB" => KeyCode::Tab,
+        "CAPSLOCK" => KeyCode::CapsLock,
+        "SPACE" => KeyCode::Space,
+        "ENTER" | "RETURN" => KeyCode::Enter,
+        "BACKSPACE" => KeyCode::Backspace,
+        "DELETE" | "DEL" => KeyCode::Delete,
+        "INSERT" => KeyCode::Insert,
+        "HOME" => KeyCode::Home,
+        "END" => KeyCode::End,
+        "PAGEUP" => KeyCode::PageUp,
+        "PAGEDOWN" => KeyCode::PageDown,
+        "LEFT" => KeyCode::ArrowLeft,
+        "RIGHT" => KeyCode::ArrowRight,
+        "UP" => KeyCode::ArrowUp,
+        "DOWN" => KeyCode::ArrowDown,
+        "PRINTSCREEN" => KeyCode::PrintScreen,
+        "SCROLLLOCK" => KeyCode::ScrollLock,
+        "PAUSE" => KeyCode::Pause,
+        "NUMLOCK" => KeyCode::NumLock,
+        "F1" => KeyCode::F1,
+        "F2" => KeyCode::F2,
+        "F3" => KeyCode::F3,
+        "F4" => KeyCode::F4,
+        "F5" => KeyCode::F5,
+        "F6" => KeyCode::F6,
+        "F7" => KeyCode::F7,
+        "F8" => KeyCode::F8,
+        "F9" => KeyCode::F9,
+        "F10" => KeyCode::F10,
+        "F11" => KeyCode::F11,
+        "F12" => KeyCode::F12,
+        "SEMICOLON" => KeyCode::Semicolon,
+        "EQUALS" => KeyCode::Equals,
+        "COMMA" => KeyCode::Comma,
+        "MINUS" => KeyCode::Minus,
+        "PERIOD" => KeyCode::Period,
+        "SLASH" => KeyCode::Slash,
+        "GRAVE" => KeyCode::Grave,
+        "LEFTBRACKET" => KeyCode::LeftBracket,
+        "RIGHTBRACKET" => KeyCode::RightBracket,
+        "BACKSLASH" => KeyCode::Backslash,
+        "APOSTROPHE" => KeyCode::Apostrophe,
+        _ if upper.len() == 1 => key_from_char(upper.chars().next().unwrap())?,
+        _ => return None,
+    })
+}
+
+fn key_from_char(c: char) -> Option<KeyCode> {
+    Some(match c {
+        'A' => KeyCode::A,
+        'B' => KeyCode::B,
+        'C' => KeyCode::C,
+        'D' => KeyCode::D,
+        'E' => KeyCode::E,
+        'F' => KeyCode::F,
+        'G' => KeyCode::G,
+        'H' => KeyCode::H,
+        'I' => KeyCode::I,
+        'J' => KeyCode::J,
+        'K' => KeyCode::K,
+        'L' => KeyCode::L,
+        'M' => KeyCode::M,
+        'N' => KeyCode::N,
+        'O' => KeyCode::O,
+        'P' => KeyCode::P,
+        'Q' => KeyCode::Q,
+        'R' => KeyCode::R,
+        'S' => KeyCode::S,
+        'T' => KeyCode::T,
+        'U' => KeyCode::U,
+        'V' => KeyCode::V,
+        'W' => KeyCode::W,
+        'X' => KeyCode::X,
+        'Y' => KeyCode::Y,
+        'Z' => KeyCode::Z,
+        '0' => KeyCode::Number0,
+        '1' => KeyCode::Number1,
+        '2' => KeyCode::Number2,
+        '3' => KeyCode::Number3,
+        '4' => KeyCode::Number4,
+        '5' => KeyCode::Number5,
+        '6' => KeyCode::Number6,
+        '7' => KeyCode::Number7,
+        '8' => KeyCode::Number8,
+        '9' => KeyCode::Number9,
+        ';' => KeyCode::Semicolon,
+        '=' => KeyCode::Equals,
+        ',' => KeyCode::Comma,
+        '-' => KeyCode::Minus,
+        '.' => KeyCode::Period,
+        '/' => KeyCode::Slash,
+        '`' => KeyCode::Grave,
+        '[' => KeyCode::LeftBracket,
+        ']' => KeyCode::RightBracket,
+        '\\' => KeyCode::Backslash,
+        '\'' => KeyCode::Apostrophe,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_multiple_bindings() {
+        let bindings = parse(
+            "# reload action\nCTRL+SHIFT+R = reload\nCTRL+SHIFT+K = my_action\n",
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(
+            bindings,
+            vec![
+                Binding {
+                    keys: vec![KeyCode::LeftControl, KeyCode::LeftShift, KeyCode::R],
+                    action: "reload".to_string(),
+                },
+                Binding {
+                    keys: vec![KeyCode::LeftControl, KeyCode::LeftShift, KeyCode::K],
+                    action: "my_action".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_key() {
+        let err = parse("CTRL+NOTAKEY = reload\n", &[]).unwrap_err();
+        assert!(matches!(
+            err,
+            ConfigError::UnknownKey { line: 1, name } if name == "NOTAKEY"
+        ));
+    }
+
+    #[test]
+    fn rejects_empty_keys() {
+        let err = parse("= reload\n", &[]).unwrap_err();
+        assert!(matches!(err, ConfigError::EmptyBinding { line: 1 }));
+    }
+
+    #[test]
+    fn rejects_empty_action() {
+        let err = parse("CTRL+R =\n", &[]).unwrap_err();
+        assert!(matches!(err, ConfigError::EmptyBinding { line: 1 }));
+    }
+
+    #[test]
+    fn rejects_duplicate_binding_within_one_parse() {
+        let err = parse("CTRL+R = reload\nCTRL+K = reload\n", &[]).unwrap_err();
+        assert!(matches!(
+            err,
+            ConfigError::DuplicateBinding { line: 2, action } if action == "reload"
+        ));
+    }
+
+    #[test]
+    fn rejects_duplicate_binding_against_existing() {
+        let existing = vec![Binding {
+            keys: vec![KeyCode::R],
+            action: "reload".to_string(),
+        }];
+        let err = parse("CTRL+K = reload\n", &existing).unwrap_err();
+        assert!(matches!(
+            err,
+            ConfigError::DuplicateBinding { line: 1, action } if action == "reload"
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_line() {
+        let err = parse("not a valid line\n", &[]).unwrap_err();
+        assert!(matches!(err, ConfigError::MalformedLine { line: 1 }));
+    }
+
+    #[test]
+    fn ignores_blank_lines_and_comments() {
+        let bindings = parse("\n# a comment\n  \nCTRL+R = reload\n", &[]).unwrap();
+        assert_eq!(
+            bindings,
+            vec![Binding {
+                keys: vec![KeyCode::LeftControl, KeyCode::R],
+                action: "reload".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn parses_punctuation_keys_by_symbol_and_by_name() {
+        let bindings = parse("CTRL+; = reload\nCTRL+COMMA = my_action\n", &[]).unwrap();
+        assert_eq!(
+            bindings,
+            vec![
+                Binding {
+                    keys: vec![KeyCode::LeftControl, KeyCode::Semicolon],
+                    action: "reload".to_string(),
+                },
+                Binding {
+                    keys: vec![KeyCode::LeftControl, KeyCode::Comma],
+                    action: "my_action".to_string(),
+                },
+            ]
+        );
+    }
+}
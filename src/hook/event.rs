@@ -0,0 +1,86 @@
+//! The richer event model produced by the low-level hooks.
+//!
+//! A plain [crate::hook::KeyCode] only tells you *which* key is involved; an [InputEvent] also
+//! carries the raw virtual-key/scan-code pair, whether the key went down or up, and the mouse
+//! data ([MouseEvent]) that the keyboard-only model had no room for at all.
+
+use crate::hook::KeyCode;
+
+/// Whether a key or mouse button transitioned to pressed or released.
+///
+/// Note that Windows will emit repeated [KeyState::Pressed] events while a key is held down
+/// (auto-repeat); there is exactly one [KeyState::Released] event per physical release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyState {
+    Pressed,
+    Released,
+}
+
+/// Identifies which mouse button a [MouseEvent::Button] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    Left,
+    Middle,
+    Right,
+    /// The first "extra" button, typically the back side-button.
+    X1,
+    /// The second "extra" button, typically the forward side-button.
+    X2,
+}
+
+/// The mouse-specific half of [InputEvent], mirroring the data Windows puts in a
+/// `MSLLHOOKSTRUCT` for each of the `WM_MOUSE*` messages the low-level mouse hook observes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseEvent {
+    /// Absolute screen position the cursor moved to.
+    Move { x: i32, y: i32 },
+    /// A mouse button changed state.
+    Button {
+        button: MouseButton,
+        state: KeyState,
+    },
+    /// Vertical wheel rotation. `delta` is the signed notch count, i.e. the `mouseData` high
+    /// word already divided by `WHEEL_DELTA` (120): `1` is one notch forward, `-1` one notch back.
+    Wheel { delta: i32 },
+    /// Horizontal wheel (tilt) rotation, same convention as [MouseEvent::Wheel].
+    HWheel { delta: i32 },
+}
+
+/// A single input event captured by a low-level hook, as returned by [crate::hook::Hook::try_recv].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputEvent {
+    /// A keyboard key changed state.
+    Keyboard {
+        key: KeyCode,
+        /// Raw virtual-key code, straight from `KBDLLHOOKSTRUCT::vkCode`.
+        vk_code: u32,
+        /// Raw hardware scan code, straight from `KBDLLHOOKSTRUCT::scanCode`.
+        scan_code: u32,
+        state: KeyState,
+        /// Whether this event was synthesized by [crate::hook::inject] (in this process or
+        /// another) rather than coming from physical hardware. See [InputEvent::is_injected].
+        injected: bool,
+    },
+    /// A mouse event; see [MouseEvent] for the specific shapes it can take.
+    Mouse {
+        event: MouseEvent,
+        /// See [InputEvent::is_injected].
+        injected: bool,
+    },
+}
+
+impl InputEvent {
+    /// Whether this event was synthesized via [crate::hook::inject] rather than coming from
+    /// physical hardware.
+    ///
+    /// By default a [crate::hook::Hook] that both listens and injects would otherwise see (and
+    /// could react to) its own synthetic input, which is rarely what's wanted; this flag lets
+    /// such consumers filter it out, while still allowing those who *do* want to observe
+    /// injected input to do so.
+    pub fn is_injected(&self) -> bool {
+        match self {
+            InputEvent::Keyboard { injected, .. } => *injected,
+            InputEvent::Mouse { injected, .. } => *injected,
+        }
+    }
+}
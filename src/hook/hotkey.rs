@@ -0,0 +1,100 @@
+//! Multi-key chord ("hotkey") detection, layered on top of the callback dispatcher in
+//! [crate::hook::registry].
+//!
+//! A held-key set is maintained from every keyboard [InputEvent], order-insensitive, and each
+//! registered combination fires its callback exactly once when the set grows to contain it, not
+//! firing again until at least one of its keys is released and the combination is re-formed.
+
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
+use crate::hook::event::{InputEvent, KeyState};
+use crate::hook::registry;
+use crate::hook::KeyCode;
+
+struct Hotkey {
+    keys: HashSet<u32>,
+    /// `true` once the combination has been released (or never yet formed), i.e. ready to fire
+    /// again the next time every key in `keys` becomes held.
+    armed: bool,
+    callback: Box<dyn Fn() + Send + Sync + 'static>,
+}
+
+#[derive(Default)]
+struct State {
+    held: HashSet<u32>,
+    hotkeys: Vec<Hotkey>,
+}
+
+static STATE: OnceLock<Mutex<State>> = OnceLock::new();
+
+fn state() -> &'static Mutex<State> {
+    STATE.get_or_init(|| Mutex::new(State::default()))
+}
+
+/// Registers `callback` to fire once when every key in `keys` is held at the same time,
+/// regardless of order. See the module docs for the re-arming rule.
+pub(super) fn register(keys: &[KeyCode], callback: impl Fn() + Send + Sync + 'static) {
+    let keys = keys.iter().copied().map(KeyCode::to_vk_code).collect();
+
+    let first = {
+        let mut state = state().lock().unwrap();
+        let first = state.hotkeys.is_empty();
+        state.hotkeys.push(Hotkey {
+            keys,
+            armed: true,
+            callback: Box::new(callback),
+        });
+        first
+    };
+
+    // [registry::reset] drops this subscription together with [reset] clearing the hotkeys, so
+    // the first hotkey registered for each [crate::hook::Hook] subscribes again.
+    if first {
+        registry::bind_any(on_event);
+    }
+}
+
+/// Drops every registered hotkey and the currently-held key set. Called from
+/// [crate::hook::inner::ActiveGuard]'s `Drop` so hotkeys don't outlive the [crate::hook::Hook]
+/// they were registered against, the same way [registry::reset] clears bound callbacks.
+pub(super) fn reset() {
+    let mut state = state().lock().unwrap();
+    state.held.clear();
+    state.hotkeys.clear();
+}
+
+fn on_event(event: InputEvent) {
+    let InputEvent::Keyboard {
+        vk_code,
+        state: key_state,
+        ..
+    } = event
+    else {
+        return;
+    };
+
+    let mut state = state().lock().unwrap();
+    match key_state {
+        // Windows re-sends "key down" for auto-repeat while a key is held; inserting into a
+        // HashSet that already contains it is a harmless no-op, so no extra tracking is needed
+        // to ignore those repeats.
+        KeyState::Pressed => {
+            state.held.insert(vk_code);
+        }
+        KeyState::Released => {
+            state.held.remove(&vk_code);
+        }
+    }
+
+    let held = state.held.clone();
+    for hotkey in state.hotkeys.iter_mut() {
+        let formed = hotkey.keys.is_subset(&held);
+        if formed && hotkey.armed {
+            (hotkey.callback)();
+            hotkey.armed = false;
+        } else if !formed {
+            hotkey.armed = true;
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Input injection: the sibling of the listening side of this crate, for *sending* synthetic
+//! keyboard and mouse input via `SendInput`.
+//!
+//! Every event sent through here is stamped with [crate::hook::inner::FAKE_EXTRA_INFO] in its
+//! `dwExtraInfo`, which the hook procs in [crate::hook::inner] recognize and flag via
+//! [crate::hook::event::InputEvent::is_injected] — so a program that both listens and injects
+//! doesn't mistake its own synthetic input for real input and loop on itself.
+
+use std::mem::size_of;
+
+use winapi::ctypes::c_int;
+use winapi::um::winuser::{
+    GetSystemMetrics, SendInput, INPUT, INPUT_KEYBOARD, INPUT_MOUSE, KEYBDINPUT, KEYEVENTF_KEYUP,
+    MOUSEEVENTF_ABSOLUTE, MOUSEEVENTF_HWHEEL, MOUSEEVENTF_LEFTDOWN, MOUSEEVENTF_LEFTUP,
+    MOUSEEVENTF_MIDDLEDOWN, MOUSEEVENTF_MIDDLEUP, MOUSEEVENTF_MOVE, MOUSEEVENTF_RIGHTDOWN,
+    MOUSEEVENTF_RIGHTUP, MOUSEEVENTF_WHEEL, MOUSEEVENTF_XDOWN, MOUSEEVENTF_XUP, MOUSEINPUT,
+    SM_CXSCREEN, SM_CYSCREEN, XBUTTON1, XBUTTON2,
+};
+
+use crate::hook::event::MouseButton;
+use crate::hook::inner::FAKE_EXTRA_INFO;
+use crate::hook::KeyCode;
+
+impl KeyCode {
+    /// Synthesizes a key-down event for this key.
+    pub fn press(self) {
+        send_key(self, false);
+    }
+
+    /// Synthesizes a key-up event for this key.
+    pub fn release(self) {
+        send_key(self, true);
+    }
+
+    /// Synthesizes a full press-then-release for this key.
+    pub fn click(self) {
+        self.press();
+        self.release();
+    }
+}
+
+fn send_key(key: KeyCode, key_up: bool) {
+    let mut flags = 0;
+    if key_up {
+        flags |= KEYEVENTF_KEYUP;
+    }
+
+    let mut keyboard: KEYBDINPUT = unsafe { std::mem::zeroed() };
+    keyboard.wVk = key.to_vk_code() as u16;
+    keyboard.dwFlags = flags;
+    keyboard.dwExtraInfo = FAKE_EXTRA_INFO;
+
+    let mut input: INPUT = unsafe { std::mem::zeroed() };
+    input.type_ = INPUT_KEYBOARD;
+    *unsafe { input.u.ki_mut() } = keyboard;
+
+    send(&mut [input]);
+}
+
+/// Moves the mouse cursor to the given absolute screen position.
+pub fn move_to(x: i32, y: i32) {
+    let x = normalize(x, SM_CXSCREEN);
+    let y = normalize(y, SM_CYSCREEN);
+    send_mouse(MOUSEEVENTF_MOVE | MOUSEEVENTF_ABSOLUTE, x, y, 0);
+}
+
+/// Maps a pixel coordinate along one screen axis into `SendInput`'s normalized 0–65535 absolute
+/// coordinate space (required by `MOUSEEVENTF_ABSOLUTE`; without it, `dx`/`dy` are instead
+/// treated as relative mickeys scaled by mouse speed/acceleration).
+fn normalize(coord: i32, axis_metric: c_int) -> i32 {
+    let extent = unsafe { GetSystemMetrics(axis_metric) }.max(1);
+    (coord as i64 * 65535 / (extent - 1).max(1) as i64) as i32
+}
+
+/// Synthesizes a press-then-release for `button` at the cursor's current position.
+pub fn click(button: MouseButton) {
+    let (down, up) = button_flags(button);
+    send_mouse(down, 0, 0, xbutton_data(button));
+    send_mouse(up, 0, 0, xbutton_data(button));
+}
+
+/// Synthesizes a vertical wheel scroll of `notches` (positive forward, negative backward).
+pub fn scroll(notches: i32) {
+    send_mouse(MOUSEEVENTF_WHEEL, 0, 0, notches * 120);
+}
+
+/// Synthesizes a horizontal wheel (tilt) scroll of `notches` (positive right, negative left).
+pub fn hscroll(notches: i32) {
+    send_mouse(MOUSEEVENTF_HWHEEL, 0, 0, notches * 120);
+}
+
+fn button_flags(button: MouseButton) -> (u32, u32) {
+    match button {
+        MouseButton::Left => (MOUSEEVENTF_LEFTDOWN, MOUSEEVENTF_LEFTUP),
+        MouseButton::Middle => (MOUSEEVENTF_MIDDLEDOWN, MOUSEEVENTF_MIDDLEUP),
+        MouseButton::Right => (MOUSEEVENTF_RIGHTDOWN, MOUSEEVENTF_RIGHTUP),
+        MouseButton::X1 | MouseButton::X2 => (MOUSEEVENTF_XDOWN, MOUSEEVENTF_XUP),
+    }
+}
+
+fn xbutton_data(button: MouseButton) -> i32 {
+    match button {
+        MouseButton::X1 => XBUTTON1 as i32,
+        MouseButton::X2 => XBUTTON2 as i32,
+        _ => 0,
+    }
+}
+
+fn send_mouse(flags: u32, dx: i32, dy: i32, mouse_data: i32) {
+    let mut mouse: MOUSEINPUT = unsafe { std::mem::zeroed() };
+    mouse.dx = dx;
+    mouse.dy = dy;
+    mouse.mouseData = mouse_data as u32;
+    mouse.dwFlags = flags;
+    mouse.dwExtraInfo = FAKE_EXTRA_INFO;
+
+    let mut input: INPUT = unsafe { std::mem::zeroed() };
+    input.type_ = INPUT_MOUSE;
+    *unsafe { input.u.mi_mut() } = mouse;
+
+    send(&mut [input]);
+}
+
+fn send(inputs: &mut [INPUT]) {
+    unsafe {
+        SendInput(
+            inputs.len() as u32,
+            inputs.as_mut_ptr(),
+            size_of::<INPUT>() as c_int,
+        );
+    }
+}
@@ -0,0 +1,289 @@
+//! Low-level background machinery backing [crate::hook::Hook]: the raw hook procedures, the
+//! background message-pump threads that install them, and the broadcast fan-out used to hand
+//! decoded [crate::hook::event::InputEvent]s to each independent consumer ([InnerHook::try_recv]
+//! and the callback dispatcher in [crate::hook::registry]).
+
+use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvError, Sender, TryRecvError};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread::{self, JoinHandle};
+
+use winapi::shared::minwindef::{LPARAM, LRESULT, WPARAM};
+use winapi::um::processthreadsapi::GetCurrentThreadId;
+use winapi::um::winuser::{
+    CallNextHookEx, DispatchMessageW, GetMessageW, PostThreadMessageW, SetWindowsHookExW,
+    TranslateMessage, UnhookWindowsHookEx, HC_ACTION, KBDLLHOOKSTRUCT, MSG, MSLLHOOKSTRUCT,
+    WHEEL_DELTA, WH_KEYBOARD_LL, WH_MOUSE_LL, WM_KEYDOWN, WM_KEYUP, WM_LBUTTONDOWN, WM_LBUTTONUP,
+    WM_MBUTTONDOWN, WM_MBUTTONUP, WM_MOUSEHWHEEL, WM_MOUSEMOVE, WM_MOUSEWHEEL, WM_QUIT,
+    WM_RBUTTONDOWN, WM_RBUTTONUP, WM_SYSKEYDOWN, WM_SYSKEYUP, WM_XBUTTONDOWN, WM_XBUTTONUP,
+};
+
+use crate::hook::event::{InputEvent, KeyState, MouseButton, MouseEvent};
+use crate::hook::{hotkey, registry, KeyCode};
+
+/// Windows low-level hooks are process-global, so only one set of ours may be installed at a
+/// time: stacking several on top of each other would make [InnerHook::try_recv] ambiguous about
+/// which hook produced a given event.
+static HOOK_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Every hook proc below broadcasts each decoded event to every channel registered here, so
+/// [InnerHook::try_recv] and the callback dispatcher (see [crate::hook::registry]) each get
+/// their own independent queue instead of racing a single shared `Receiver` for the same event.
+static BROADCAST: OnceLock<Mutex<Vec<Sender<InputEvent>>>> = OnceLock::new();
+
+fn broadcast_list() -> &'static Mutex<Vec<Sender<InputEvent>>> {
+    BROADCAST.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+/// Registers a new independent listener and returns its `Receiver`; every event subsequently
+/// broadcast by a hook proc is cloned to it, regardless of how many other listeners exist.
+fn register_listener() -> Receiver<InputEvent> {
+    let (sender, receiver) = mpsc::channel();
+    broadcast_list().lock().unwrap().push(sender);
+    receiver
+}
+
+fn broadcast(event: InputEvent) {
+    let senders = broadcast_list().lock().unwrap();
+    for sender in senders.iter() {
+        let _ = sender.send(event);
+    }
+}
+
+/// Stamped into `dwExtraInfo` of every event [crate::hook::inject] sends via `SendInput`, so the
+/// hook procs below can recognize this process's own synthetic input (flagging it as
+/// [crate::hook::event::InputEvent::is_injected]) instead of mistaking it for real hardware input.
+pub(super) const FAKE_EXTRA_INFO: usize = 0x5749_4C4C; // "WILL", arbitrary but recognizable
+
+/// The opt-in suppression predicate installed via [crate::hook::HookBuilder::with_suppression],
+/// consulted synchronously from the hook procs below. `None` means nothing is ever suppressed.
+type SuppressionPredicate = Box<dyn Fn(&InputEvent) -> bool + Send + Sync + 'static>;
+static SUPPRESSION: OnceLock<Mutex<Option<SuppressionPredicate>>> = OnceLock::new();
+
+fn suppression() -> &'static Mutex<Option<SuppressionPredicate>> {
+    SUPPRESSION.get_or_init(|| Mutex::new(None))
+}
+
+/// Installs the predicate that decides whether an event gets suppressed, replacing any previous
+/// one. Cleared automatically when the owning [ActiveGuard] is dropped.
+pub(super) fn install_suppression(predicate: impl Fn(&InputEvent) -> bool + Send + Sync + 'static) {
+    *suppression().lock().unwrap() = Some(Box::new(predicate));
+}
+
+fn should_suppress(event: &InputEvent) -> bool {
+    match suppression().lock().unwrap().as_ref() {
+        Some(predicate) => predicate(event),
+        None => false,
+    }
+}
+
+/// Held by [crate::hook::Hook] for as long as any low-level hook is installed; dropping it frees
+/// [HOOK_ACTIVE] so a later [crate::hook::HookBuilder::build] can succeed again, and clears any
+/// suppression predicate, bound callback ([crate::hook::registry]), and registered hotkey
+/// ([crate::hook::hotkey]) installed for this hook's lifetime — none of them are meant to outlive
+/// the [crate::hook::Hook] they were registered against.
+pub(super) struct ActiveGuard(());
+
+impl Drop for ActiveGuard {
+    fn drop(&mut self) {
+        *suppression().lock().unwrap() = None;
+        registry::reset();
+        hotkey::reset();
+        HOOK_ACTIVE.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Reserves the single process-wide hook slot, or returns `None` if it is already taken.
+pub(super) fn try_acquire() -> Option<ActiveGuard> {
+    HOOK_ACTIVE
+        .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+        .ok()
+        .map(|_| ActiveGuard(()))
+}
+
+/// Handle to a background thread running a Windows message loop with a single low-level hook
+/// (keyboard xor mouse) installed on it. Dropping it asks the thread to unhook and exit, then
+/// joins it, so the low-level hook is guaranteed gone once the drop returns.
+pub(super) struct InnerHook {
+    thread_id: u32,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for InnerHook {
+    fn drop(&mut self) {
+        unsafe {
+            PostThreadMessageW(self.thread_id, WM_QUIT, 0, 0);
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// [InnerHook::try_recv]'s own listener, independent of the dispatcher's: both see every event.
+static TRY_RECV_LISTENER: OnceLock<Mutex<Receiver<InputEvent>>> = OnceLock::new();
+
+fn try_recv_listener() -> &'static Mutex<Receiver<InputEvent>> {
+    TRY_RECV_LISTENER.get_or_init(|| Mutex::new(register_listener()))
+}
+
+/// The callback dispatcher's own listener (see [crate::hook::registry::dispatch_loop]),
+/// independent of [try_recv_listener]: both see every event.
+static DISPATCH_LISTENER: OnceLock<Mutex<Receiver<InputEvent>>> = OnceLock::new();
+
+fn dispatch_listener() -> &'static Mutex<Receiver<InputEvent>> {
+    DISPATCH_LISTENER.get_or_init(|| Mutex::new(register_listener()))
+}
+
+impl InnerHook {
+    /// Tries to receive the next decoded [InputEvent] recorded by any currently active hook.
+    ///
+    /// Independent of the callback dispatcher started by [crate::hook::Hook::bind] et al: every
+    /// event is delivered to both, so mixing `try_recv` polling with bound callbacks is safe.
+    pub(super) fn try_recv() -> Result<InputEvent, TryRecvError> {
+        try_recv_listener().lock().unwrap().try_recv()
+    }
+
+    /// Blocks until the next decoded [InputEvent] arrives. Used by the callback dispatcher in
+    /// [crate::hook::registry], which has nothing better to do while waiting.
+    pub(super) fn recv() -> Result<InputEvent, RecvError> {
+        dispatch_listener().lock().unwrap().recv()
+    }
+}
+
+type HookProc = unsafe extern "system" fn(i32, WPARAM, LPARAM) -> LRESULT;
+
+/// Spawns the background thread that installs `WH_KEYBOARD_LL` and pumps its messages.
+pub(super) fn setup_keyboard_hook() -> Option<Arc<InnerHook>> {
+    spawn_hook_thread(WH_KEYBOARD_LL, keyboard_proc).map(Arc::new)
+}
+
+/// Spawns the background thread that installs `WH_MOUSE_LL` and pumps its messages.
+pub(super) fn setup_mouse_hook() -> Option<Arc<InnerHook>> {
+    spawn_hook_thread(WH_MOUSE_LL, mouse_proc).map(Arc::new)
+}
+
+fn spawn_hook_thread(id: i32, proc: HookProc) -> Option<InnerHook> {
+    let (ready_tx, ready_rx) = mpsc::channel::<Option<u32>>();
+
+    // Register try_recv's listener before the hook exists, so events recorded between the build
+    // and the first try_recv call are queued for it rather than lost.
+    try_recv_listener();
+
+    let thread = thread::spawn(move || unsafe {
+        let hook = SetWindowsHookExW(id, Some(proc), ptr::null_mut(), 0);
+        if hook.is_null() {
+            let _ = ready_tx.send(None);
+            return;
+        }
+        let _ = ready_tx.send(Some(GetCurrentThreadId()));
+
+        let mut msg: MSG = std::mem::zeroed();
+        while GetMessageW(&mut msg, ptr::null_mut(), 0, 0) > 0 {
+            TranslateMessage(&msg);
+            DispatchMessageW(&msg);
+        }
+
+        UnhookWindowsHookEx(hook);
+    });
+
+    match ready_rx.recv() {
+        Ok(Some(thread_id)) => Some(InnerHook {
+            thread_id,
+            thread: Some(thread),
+        }),
+        _ => {
+            let _ = thread.join();
+            None
+        }
+    }
+}
+
+unsafe extern "system" fn keyboard_proc(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    if code == HC_ACTION as i32 {
+        let data = &*(lparam as *const KBDLLHOOKSTRUCT);
+        let state = match wparam as u32 {
+            WM_KEYDOWN | WM_SYSKEYDOWN => Some(KeyState::Pressed),
+            WM_KEYUP | WM_SYSKEYUP => Some(KeyState::Released),
+            _ => None,
+        };
+        if let Some(state) = state {
+            let event = InputEvent::Keyboard {
+                key: KeyCode::from(data.vkCode),
+                vk_code: data.vkCode,
+                scan_code: data.scanCode,
+                state,
+                injected: data.dwExtraInfo == FAKE_EXTRA_INFO,
+            };
+            let blocked = should_suppress(&event);
+            broadcast(event);
+            if blocked {
+                // Non-zero return from a WH_KEYBOARD_LL proc tells Windows to swallow the
+                // event instead of forwarding it to CallNextHookEx / the rest of the system.
+                return 1;
+            }
+        }
+    }
+    CallNextHookEx(ptr::null_mut(), code, wparam, lparam)
+}
+
+unsafe extern "system" fn mouse_proc(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    if code == HC_ACTION as i32 {
+        let data = &*(lparam as *const MSLLHOOKSTRUCT);
+        let event = match wparam as u32 {
+            WM_MOUSEMOVE => Some(MouseEvent::Move {
+                x: data.pt.x,
+                y: data.pt.y,
+            }),
+            WM_LBUTTONDOWN => Some(button(MouseButton::Left, KeyState::Pressed)),
+            WM_LBUTTONUP => Some(button(MouseButton::Left, KeyState::Released)),
+            WM_RBUTTONDOWN => Some(button(MouseButton::Right, KeyState::Pressed)),
+            WM_RBUTTONUP => Some(button(MouseButton::Right, KeyState::Released)),
+            WM_MBUTTONDOWN => Some(button(MouseButton::Middle, KeyState::Pressed)),
+            WM_MBUTTONUP => Some(button(MouseButton::Middle, KeyState::Released)),
+            WM_XBUTTONDOWN => Some(button(x_button(data.mouseData), KeyState::Pressed)),
+            WM_XBUTTONUP => Some(button(x_button(data.mouseData), KeyState::Released)),
+            WM_MOUSEWHEEL => Some(MouseEvent::Wheel {
+                delta: wheel_delta(data.mouseData),
+            }),
+            WM_MOUSEHWHEEL => Some(MouseEvent::HWheel {
+                delta: wheel_delta(data.mouseData),
+            }),
+            _ => None,
+        };
+        if let Some(event) = event {
+            let event = InputEvent::Mouse {
+                event,
+                injected: data.dwExtraInfo == FAKE_EXTRA_INFO,
+            };
+            let blocked = should_suppress(&event);
+            broadcast(event);
+            if blocked {
+                // Same convention as keyboard_proc: non-zero blocks the event from propagating.
+                return 1;
+            }
+        }
+    }
+    CallNextHookEx(ptr::null_mut(), code, wparam, lparam)
+}
+
+fn button(button: MouseButton, state: KeyState) -> MouseEvent {
+    MouseEvent::Button { button, state }
+}
+
+/// Extracts which X-button (`XBUTTON1`/`XBUTTON2`) triggered a `WM_XBUTTONDOWN`/`WM_XBUTTONUP`,
+/// encoded in the high word of `MSLLHOOKSTRUCT::mouseData`.
+fn x_button(mouse_data: u32) -> MouseButton {
+    if (mouse_data >> 16) & 0xFFFF == 1 {
+        MouseButton::X1
+    } else {
+        MouseButton::X2
+    }
+}
+
+/// The notch count from `MSLLHOOKSTRUCT::mouseData`'s high word: a signed multiple of
+/// `WHEEL_DELTA` (120), positive for forward/right, negative for backward/left.
+fn wheel_delta(mouse_data: u32) -> i32 {
+    ((mouse_data >> 16) & 0xFFFF) as i16 as i32 / WHEEL_DELTA as i32
+}
@@ -0,0 +1,382 @@
+//! [KeyCode]: the set of keys the crate can tell apart.
+
+/// Identifies a physical key, independent of its up/down state (see [crate::hook::event::KeyState]
+/// for that) or of which hook observed it.
+///
+/// Variants map onto the Windows virtual-key (`VK_*`) constants via [KeyCode::from_vk_code]; any
+/// virtual-key code this crate doesn't have a named variant for is preserved as [KeyCode::Other]
+/// rather than dropped, so consumers never silently lose an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyCode {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+    K,
+    L,
+    M,
+    N,
+    O,
+    P,
+    Q,
+    R,
+    S,
+    T,
+    U,
+    V,
+    W,
+    X,
+    Y,
+    Z,
+    Number0,
+    Number1,
+    Number2,
+    Number3,
+    Number4,
+    Number5,
+    Number6,
+    Number7,
+    Number8,
+    Number9,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+    F13,
+    F14,
+    F15,
+    F16,
+    F17,
+    F18,
+    F19,
+    F20,
+    F21,
+    F22,
+    F23,
+    F24,
+    Escape,
+    Tab,
+    CapsLock,
+    Space,
+    Enter,
+    Backspace,
+    Delete,
+    Insert,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    ArrowLeft,
+    ArrowRight,
+    ArrowUp,
+    ArrowDown,
+    PrintScreen,
+    ScrollLock,
+    Pause,
+    NumLock,
+    LeftShift,
+    RightShift,
+    LeftControl,
+    RightControl,
+    LeftAlt,
+    RightAlt,
+    LeftWindows,
+    RightWindows,
+    Numpad0,
+    Numpad1,
+    Numpad2,
+    Numpad3,
+    Numpad4,
+    Numpad5,
+    Numpad6,
+    Numpad7,
+    Numpad8,
+    Numpad9,
+    NumpadAdd,
+    NumpadSubtract,
+    NumpadMultiply,
+    NumpadDivide,
+    NumpadDecimal,
+    Semicolon,
+    Equals,
+    Comma,
+    Minus,
+    Period,
+    Slash,
+    Grave,
+    LeftBracket,
+    RightBracket,
+    Backslash,
+    Apostrophe,
+    /// A virtual-key code this crate doesn't (yet) have a named variant for.
+    Other(u32),
+}
+
+impl KeyCode {
+    /// Maps a raw Windows virtual-key code (e.g. `KBDLLHOOKSTRUCT::vkCode`) to a [KeyCode].
+    pub fn from_vk_code(vk_code: u32) -> Self {
+        use winapi::um::winuser::*;
+
+        match vk_code as i32 {
+            0x41 => KeyCode::A,
+            0x42 => KeyCode::B,
+            0x43 => KeyCode::C,
+            0x44 => KeyCode::D,
+            0x45 => KeyCode::E,
+            0x46 => KeyCode::F,
+            0x47 => KeyCode::G,
+            0x48 => KeyCode::H,
+            0x49 => KeyCode::I,
+            0x4A => KeyCode::J,
+            0x4B => KeyCode::K,
+            0x4C => KeyCode::L,
+            0x4D => KeyCode::M,
+            0x4E => KeyCode::N,
+            0x4F => KeyCode::O,
+            0x50 => KeyCode::P,
+            0x51 => KeyCode::Q,
+            0x52 => KeyCode::R,
+            0x53 => KeyCode::S,
+            0x54 => KeyCode::T,
+            0x55 => KeyCode::U,
+            0x56 => KeyCode::V,
+            0x57 => KeyCode::W,
+            0x58 => KeyCode::X,
+            0x59 => KeyCode::Y,
+            0x5A => KeyCode::Z,
+            0x30 => KeyCode::Number0,
+            0x31 => KeyCode::Number1,
+            0x32 => KeyCode::Number2,
+            0x33 => KeyCode::Number3,
+            0x34 => KeyCode::Number4,
+            0x35 => KeyCode::Number5,
+            0x36 => KeyCode::Number6,
+            0x37 => KeyCode::Number7,
+            0x38 => KeyCode::Number8,
+            0x39 => KeyCode::Number9,
+            VK_F1 => KeyCode::F1,
+            VK_F2 => KeyCode::F2,
+            VK_F3 => KeyCode::F3,
+            VK_F4 => KeyCode::F4,
+            VK_F5 => KeyCode::F5,
+            VK_F6 => KeyCode::F6,
+            VK_F7 => KeyCode::F7,
+            VK_F8 => KeyCode::F8,
+            VK_F9 => KeyCode::F9,
+            VK_F10 => KeyCode::F10,
+            VK_F11 => KeyCode::F11,
+            VK_F12 => KeyCode::F12,
+            VK_F13 => KeyCode::F13,
+            VK_F14 => KeyCode::F14,
+            VK_F15 => KeyCode::F15,
+            VK_F16 => KeyCode::F16,
+            VK_F17 => KeyCode::F17,
+            VK_F18 => KeyCode::F18,
+            VK_F19 => KeyCode::F19,
+            VK_F20 => KeyCode::F20,
+            VK_F21 => KeyCode::F21,
+            VK_F22 => KeyCode::F22,
+            VK_F23 => KeyCode::F23,
+            VK_F24 => KeyCode::F24,
+            VK_ESCAPE => KeyCode::Escape,
+            VK_TAB => KeyCode::Tab,
+            VK_CAPITAL => KeyCode::CapsLock,
+            VK_SPACE => KeyCode::Space,
+            VK_RETURN => KeyCode::Enter,
+            VK_BACK => KeyCode::Backspace,
+            VK_DELETE => KeyCode::Delete,
+            VK_INSERT => KeyCode::Insert,
+            VK_HOME => KeyCode::Home,
+            VK_END => KeyCode::End,
+            VK_PRIOR => KeyCode::PageUp,
+            VK_NEXT => KeyCode::PageDown,
+            VK_LEFT => KeyCode::ArrowLeft,
+            VK_RIGHT => KeyCode::ArrowRight,
+            VK_UP => KeyCode::ArrowUp,
+            VK_DOWN => KeyCode::ArrowDown,
+            VK_SNAPSHOT => KeyCode::PrintScreen,
+            VK_SCROLL => KeyCode::ScrollLock,
+            VK_PAUSE => KeyCode::Pause,
+            VK_NUMLOCK => KeyCode::NumLock,
+            VK_LSHIFT => KeyCode::LeftShift,
+            VK_RSHIFT => KeyCode::RightShift,
+            VK_LCONTROL => KeyCode::LeftControl,
+            VK_RCONTROL => KeyCode::RightControl,
+            VK_LMENU => KeyCode::LeftAlt,
+            VK_RMENU => KeyCode::RightAlt,
+            VK_LWIN => KeyCode::LeftWindows,
+            VK_RWIN => KeyCode::RightWindows,
+            VK_NUMPAD0 => KeyCode::Numpad0,
+            VK_NUMPAD1 => KeyCode::Numpad1,
+            VK_NUMPAD2 => KeyCode::Numpad2,
+            VK_NUMPAD3 => KeyCode::Numpad3,
+            VK_NUMPAD4 => KeyCode::Numpad4,
+            VK_NUMPAD5 => KeyCode::Numpad5,
+            VK_NUMPAD6 => KeyCode::Numpad6,
+            VK_NUMPAD7 => KeyCode::Numpad7,
+            VK_NUMPAD8 => KeyCode::Numpad8,
+            VK_NUMPAD9 => KeyCode::Numpad9,
+            VK_ADD => KeyCode::NumpadAdd,
+            VK_SUBTRACT => KeyCode::NumpadSubtract,
+            VK_MULTIPLY => KeyCode::NumpadMultiply,
+            VK_DIVIDE => KeyCode::NumpadDivide,
+            VK_DECIMAL => KeyCode::NumpadDecimal,
+            VK_OEM_1 => KeyCode::Semicolon,
+            VK_OEM_PLUS => KeyCode::Equals,
+            VK_OEM_COMMA => KeyCode::Comma,
+            VK_OEM_MINUS => KeyCode::Minus,
+            VK_OEM_PERIOD => KeyCode::Period,
+            VK_OEM_2 => KeyCode::Slash,
+            VK_OEM_3 => KeyCode::Grave,
+            VK_OEM_4 => KeyCode::LeftBracket,
+            VK_OEM_6 => KeyCode::RightBracket,
+            VK_OEM_5 => KeyCode::Backslash,
+            VK_OEM_7 => KeyCode::Apostrophe,
+            _ => KeyCode::Other(vk_code),
+        }
+    }
+
+    /// Maps this [KeyCode] back to the raw Windows virtual-key code it came from. The inverse
+    /// of [KeyCode::from_vk_code].
+    pub fn to_vk_code(self) -> u32 {
+        use winapi::um::winuser::*;
+
+        (match self {
+            KeyCode::A => 0x41,
+            KeyCode::B => 0x42,
+            KeyCode::C => 0x43,
+            KeyCode::D => 0x44,
+            KeyCode::E => 0x45,
+            KeyCode::F => 0x46,
+            KeyCode::G => 0x47,
+            KeyCode::H => 0x48,
+            KeyCode::I => 0x49,
+            KeyCode::J => 0x4A,
+            KeyCode::K => 0x4B,
+            KeyCode::L => 0x4C,
+            KeyCode::M => 0x4D,
+            KeyCode::N => 0x4E,
+            KeyCode::O => 0x4F,
+            KeyCode::P => 0x50,
+            KeyCode::Q => 0x51,
+            KeyCode::R => 0x52,
+            KeyCode::S => 0x53,
+            KeyCode::T => 0x54,
+            KeyCode::U => 0x55,
+            KeyCode::V => 0x56,
+            KeyCode::W => 0x57,
+            KeyCode::X => 0x58,
+            KeyCode::Y => 0x59,
+            KeyCode::Z => 0x5A,
+            KeyCode::Number0 => 0x30,
+            KeyCode::Number1 => 0x31,
+            KeyCode::Number2 => 0x32,
+            KeyCode::Number3 => 0x33,
+            KeyCode::Number4 => 0x34,
+            KeyCode::Number5 => 0x35,
+            KeyCode::Number6 => 0x36,
+            KeyCode::Number7 => 0x37,
+            KeyCode::Number8 => 0x38,
+            KeyCode::Number9 => 0x39,
+            KeyCode::F1 => VK_F1,
+            KeyCode::F2 => VK_F2,
+            KeyCode::F3 => VK_F3,
+            KeyCode::F4 => VK_F4,
+            KeyCode::F5 => VK_F5,
+            KeyCode::F6 => VK_F6,
+            KeyCode::F7 => VK_F7,
+            KeyCode::F8 => VK_F8,
+            KeyCode::F9 => VK_F9,
+            KeyCode::F10 => VK_F10,
+            KeyCode::F11 => VK_F11,
+            KeyCode::F12 => VK_F12,
+            KeyCode::F13 => VK_F13,
+            KeyCode::F14 => VK_F14,
+            KeyCode::F15 => VK_F15,
+            KeyCode::F16 => VK_F16,
+            KeyCode::F17 => VK_F17,
+            KeyCode::F18 => VK_F18,
+            KeyCode::F19 => VK_F19,
+            KeyCode::F20 => VK_F20,
+            KeyCode::F21 => VK_F21,
+            KeyCode::F22 => VK_F22,
+            KeyCode::F23 => VK_F23,
+            KeyCode::F24 => VK_F24,
+            KeyCode::Escape => VK_ESCAPE,
+            KeyCode::Tab => VK_TAB,
+            KeyCode::CapsLock => VK_CAPITAL,
+            KeyCode::Space => VK_SPACE,
+            KeyCode::Enter => VK_RETURN,
+            KeyCode::Backspace => VK_BACK,
+            KeyCode::Delete => VK_DELETE,
+            KeyCode::Insert => VK_INSERT,
+            KeyCode::Home => VK_HOME,
+            KeyCode::End => VK_END,
+            KeyCode::PageUp => VK_PRIOR,
+            KeyCode::PageDown => VK_NEXT,
+            KeyCode::ArrowLeft => VK_LEFT,
+            KeyCode::ArrowRight => VK_RIGHT,
+            KeyCode::ArrowUp => VK_UP,
+            KeyCode::ArrowDown => VK_DOWN,
+            KeyCode::PrintScreen => VK_SNAPSHOT,
+            KeyCode::ScrollLock => VK_SCROLL,
+            KeyCode::Pause => VK_PAUSE,
+            KeyCode::NumLock => VK_NUMLOCK,
+            KeyCode::LeftShift => VK_LSHIFT,
+            KeyCode::RightShift => VK_RSHIFT,
+            KeyCode::LeftControl => VK_LCONTROL,
+            KeyCode::RightControl => VK_RCONTROL,
+            KeyCode::LeftAlt => VK_LMENU,
+            KeyCode::RightAlt => VK_RMENU,
+            KeyCode::LeftWindows => VK_LWIN,
+            KeyCode::RightWindows => VK_RWIN,
+            KeyCode::Numpad0 => VK_NUMPAD0,
+            KeyCode::Numpad1 => VK_NUMPAD1,
+            KeyCode::Numpad2 => VK_NUMPAD2,
+            KeyCode::Numpad3 => VK_NUMPAD3,
+            KeyCode::Numpad4 => VK_NUMPAD4,
+            KeyCode::Numpad5 => VK_NUMPAD5,
+            KeyCode::Numpad6 => VK_NUMPAD6,
+            KeyCode::Numpad7 => VK_NUMPAD7,
+            KeyCode::Numpad8 => VK_NUMPAD8,
+            KeyCode::Numpad9 => VK_NUMPAD9,
+            KeyCode::NumpadAdd => VK_ADD,
+            KeyCode::NumpadSubtract => VK_SUBTRACT,
+            KeyCode::NumpadMultiply => VK_MULTIPLY,
+            KeyCode::NumpadDivide => VK_DIVIDE,
+            KeyCode::NumpadDecimal => VK_DECIMAL,
+            KeyCode::Semicolon => VK_OEM_1,
+            KeyCode::Equals => VK_OEM_PLUS,
+            KeyCode::Comma => VK_OEM_COMMA,
+            KeyCode::Minus => VK_OEM_MINUS,
+            KeyCode::Period => VK_OEM_PERIOD,
+            KeyCode::Slash => VK_OEM_2,
+            KeyCode::Grave => VK_OEM_3,
+            KeyCode::LeftBracket => VK_OEM_4,
+            KeyCode::RightBracket => VK_OEM_6,
+            KeyCode::Backslash => VK_OEM_5,
+            KeyCode::Apostrophe => VK_OEM_7,
+            KeyCode::Other(vk_code) => return vk_code,
+        }) as u32
+    }
+}
+
+impl From<u32> for KeyCode {
+    fn from(vk_code: u32) -> Self {
+        KeyCode::from_vk_code(vk_code)
+    }
+}
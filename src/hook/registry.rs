@@ -0,0 +1,106 @@
+//! Callback/subscription layer sitting alongside [crate::hook::Hook::try_recv].
+//!
+//! Binding a callback starts a single background dispatcher thread that drains its own
+//! listener registered with [crate::hook::inner] and invokes matching callbacks on it, so user
+//! code runs off the hook thread and can't make Windows time out the low-level hook. That
+//! listener is independent of [crate::hook::Hook::try_recv]'s: every event reaches both, so a
+//! caller is free to mix polling and bound callbacks without either side starving the other.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread;
+
+use crate::hook::event::{InputEvent, MouseButton, MouseEvent};
+use crate::hook::inner::InnerHook;
+use crate::hook::KeyCode;
+
+/// What an [InputEvent] has to match for a [crate::hook::Hook::bind] callback to fire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Trigger {
+    /// Fires when the given key changes state (press or release).
+    Key(KeyCode),
+    /// Fires when the given mouse button changes state (press or release).
+    MouseButton(MouseButton),
+}
+
+impl Trigger {
+    fn matching(event: &InputEvent) -> Option<Trigger> {
+        match event {
+            InputEvent::Keyboard { key, .. } => Some(Trigger::Key(*key)),
+            InputEvent::Mouse {
+                event: MouseEvent::Button { button, .. },
+                ..
+            } => Some(Trigger::MouseButton(*button)),
+            InputEvent::Mouse { .. } => None,
+        }
+    }
+}
+
+type Callback = Arc<dyn Fn(InputEvent) + Send + Sync + 'static>;
+
+#[derive(Default)]
+struct Registry {
+    bound: HashMap<Trigger, Vec<Callback>>,
+    any: Vec<Callback>,
+}
+
+static REGISTRY: OnceLock<Mutex<Registry>> = OnceLock::new();
+static DISPATCHER: OnceLock<()> = OnceLock::new();
+
+fn registry() -> &'static Mutex<Registry> {
+    REGISTRY.get_or_init(|| Mutex::new(Registry::default()))
+}
+
+/// Registers `callback` to run whenever an [InputEvent] matching `trigger` arrives.
+pub(super) fn bind(trigger: Trigger, callback: impl Fn(InputEvent) + Send + Sync + 'static) {
+    ensure_dispatcher();
+    registry()
+        .lock()
+        .unwrap()
+        .bound
+        .entry(trigger)
+        .or_default()
+        .push(Arc::new(callback));
+}
+
+/// Registers `callback` to run for every [InputEvent], regardless of [Trigger].
+pub(super) fn bind_any(callback: impl Fn(InputEvent) + Send + Sync + 'static) {
+    ensure_dispatcher();
+    registry().lock().unwrap().any.push(Arc::new(callback));
+}
+
+/// Drops every bound callback. Called from [crate::hook::inner::ActiveGuard]'s `Drop` so
+/// bindings don't outlive the [crate::hook::Hook] they were registered against: the dispatcher
+/// thread itself keeps running (there's no cheap way to stop it, and the next `Hook` needs it
+/// again anyway), it just has nothing left to call until new callbacks are bound.
+pub(super) fn reset() {
+    let mut registry = registry().lock().unwrap();
+    registry.bound.clear();
+    registry.any.clear();
+}
+
+/// Starts the background dispatcher thread the first time anything is bound; subsequent calls
+/// are no-ops, since one thread is enough to serve every registered callback.
+fn ensure_dispatcher() {
+    DISPATCHER.get_or_init(|| {
+        thread::spawn(dispatch_loop);
+    });
+}
+
+fn dispatch_loop() {
+    while let Ok(event) = InnerHook::recv() {
+        let callbacks = {
+            let registry = registry().lock().unwrap();
+            let mut callbacks = registry.any.clone();
+            if let Some(trigger) = Trigger::matching(&event) {
+                if let Some(bound) = registry.bound.get(&trigger) {
+                    callbacks.extend(bound.iter().cloned());
+                }
+            }
+            callbacks
+        };
+        for callback in callbacks {
+            callback(event);
+        }
+    }
+}